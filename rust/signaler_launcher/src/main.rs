@@ -1,13 +1,32 @@
 use std::env;
-use std::path::PathBuf;
-use std::process::Command;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::time::UNIX_EPOCH;
+
+use tracing::{debug, debug_span, instrument};
+use tracing_subscriber::EnvFilter;
+
+/// Launcher-managed flag. It is only recognised ahead of the CLI subcommand
+/// (`signaler --no-preflight audit ...`); anywhere else it is forwarded untouched.
+const NO_PREFLIGHT_FLAG: &str = "--no-preflight";
+const LOG_ENV: &str = "SIGNALER_LOG";
 
 fn main() {
+    init_logging();
     match run() {
         Ok(status) => {
-            std::process::exit(status.code().unwrap_or(0));
+            std::process::exit(exit_code_for(status));
         }
-        Err(error) => {
+        Err(LaunchError::CorruptEntrypoint(error)) => {
+            eprintln!("signaler launcher error: {error}");
+            eprintln!();
+            eprintln!("Reinstall Signaler to restore dist/, or skip this check with:");
+            eprintln!("  signaler {NO_PREFLIGHT_FLAG} <command> ...");
+            std::process::exit(1);
+        }
+        Err(LaunchError::Environment(error)) => {
             eprintln!("signaler launcher error: {error}");
             eprintln!();
             eprintln!("Requirements:");
@@ -20,35 +39,240 @@ fn main() {
     }
 }
 
-fn run() -> Result<std::process::ExitStatus, String> {
+/// Diagnostics are off unless `SIGNALER_LOG` is set, using `RUST_LOG` filter syntax
+/// (e.g. `SIGNALER_LOG=debug`). User-facing errors are always printed regardless.
+fn init_logging() {
+    let directives = env::var(LOG_ENV).unwrap_or_default();
+    let filter = if directives.trim().is_empty() {
+        EnvFilter::new("off")
+    } else {
+        EnvFilter::try_new(directives.trim()).unwrap_or_else(|error| {
+            eprintln!("signaler launcher warning: ignoring invalid {LOG_ENV} ({error})");
+            EnvFilter::new("off")
+        })
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(false)
+        .init();
+}
+
+enum LaunchError {
+    /// Node or the install root could not be found or started.
+    Environment(String),
+    /// The install was found but its CLI entrypoint failed the preflight.
+    CorruptEntrypoint(String),
+}
+
+impl From<String> for LaunchError {
+    fn from(error: String) -> Self {
+        LaunchError::Environment(error)
+    }
+}
+
+fn run() -> Result<std::process::ExitStatus, LaunchError> {
     let install_root = resolve_install_root()?;
     let node = find_node_executable()?;
     let bin_js = install_root.join("dist").join("bin.js");
     if !bin_js.is_file() {
-        return Err(format!(
+        return Err(LaunchError::Environment(format!(
             "missing CLI entrypoint at {} (install root: {})",
             bin_js.display(),
             install_root.display()
-        ));
+        )));
     }
 
-    let args: Vec<String> = env::args().skip(1).collect();
+    let (args, skip_preflight) = take_launcher_flags(env::args().skip(1).collect());
+    if skip_preflight {
+        debug!("preflight skipped via {NO_PREFLIGHT_FLAG}");
+    } else {
+        preflight_entrypoint(&node, &bin_js)?;
+    }
+
+    let _span = debug_span!("spawn_cli", node = %node, bin_js = %bin_js.display()).entered();
+    debug!(?args, "delegating to Node CLI");
     let mut command = Command::new(&node);
     command.arg(&bin_js);
     command.args(args);
-    command
+    let status = command
         .status()
-        .map_err(|error| format!("failed to spawn Node.js ({node}): {error}"))
+        .map_err(|error| format!("failed to spawn Node.js ({node}): {error}"))?;
+    debug!(%status, "Node CLI exited");
+    Ok(status)
+}
+
+/// Strips launcher-managed flags from the leading arguments and returns the
+/// remaining argv for the CLI plus whether the preflight should be skipped.
+fn take_launcher_flags(args: Vec<String>) -> (Vec<String>, bool) {
+    let leading = args
+        .iter()
+        .take_while(|arg| arg.as_str() == NO_PREFLIGHT_FLAG)
+        .count();
+    let skip_preflight = leading > 0;
+    (args.into_iter().skip(leading).collect(), skip_preflight)
 }
 
+/// Catches a truncated or corrupt `dist/bin.js` before delegating. `node --check` only
+/// parses the entry file itself, not the ES modules it imports, so a corrupt file elsewhere
+/// in `dist/` still surfaces as a Node error at runtime.
+#[instrument(level = "debug", skip(node), fields(bin_js = %bin_js.display()))]
+fn preflight_entrypoint(node: &str, bin_js: &Path) -> Result<(), LaunchError> {
+    // An empty file parses cleanly under `node --check`, so catch truncated installs first.
+    let is_empty = fs::metadata(bin_js).is_ok_and(|metadata| metadata.len() == 0);
+    if is_empty {
+        debug!("preflight failed: entrypoint is empty");
+        return Err(LaunchError::CorruptEntrypoint(format!(
+            "CLI entrypoint at {} is empty; the install is likely truncated",
+            bin_js.display()
+        )));
+    }
+    // A pass is cached per entrypoint and Node binary (size and mtime of each) so only the
+    // first invocation after an install or upgrade pays for the extra Node process.
+    let stamp = preflight_stamp(node, bin_js);
+    if let Some((stamp_path, key)) = &stamp {
+        if fs::read_to_string(stamp_path).is_ok_and(|cached| cached == *key) {
+            debug!("preflight pass cached");
+            return Ok(());
+        }
+    }
+    let output = Command::new(node)
+        .arg("--check")
+        .arg(bin_js)
+        .output()
+        .map_err(|error| format!("failed to spawn Node.js ({node}): {error}"))?;
+    if output.status.success() {
+        if let Some((stamp_path, key)) = &stamp {
+            write_stamp(stamp_path, key);
+        }
+        debug!("preflight passed");
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let detail = stderr
+        .lines()
+        .map(str::trim)
+        .find(|line| line.contains("Error"))
+        .or_else(|| stderr.lines().map(str::trim).find(|line| !line.is_empty()))
+        .unwrap_or("no diagnostic output");
+    debug!(
+        detail,
+        "preflight failed: node --check rejected the entrypoint"
+    );
+    Err(LaunchError::CorruptEntrypoint(format!(
+        "CLI entrypoint at {} failed the syntax preflight ({detail}); the entry file is likely corrupt (only dist/bin.js itself is checked, not the modules it imports)",
+        bin_js.display()
+    )))
+}
+
+fn preflight_stamp(node: &str, bin_js: &Path) -> Option<(PathBuf, String)> {
+    let key = preflight_stamp_key(Path::new(node), bin_js)?;
+    let mut identity = bin_js.as_os_str().as_encoded_bytes().to_vec();
+    identity.push(0);
+    identity.extend_from_slice(node.as_bytes());
+    let name = format!("{:016x}.stamp", fnv1a_64(&identity));
+    Some((preflight_cache_dir()?.join(name), key))
+}
+
+fn preflight_stamp_key(node: &Path, bin_js: &Path) -> Option<String> {
+    Some(format!(
+        "{}|{}",
+        file_fingerprint(bin_js)?,
+        file_fingerprint(node)?
+    ))
+}
+
+fn file_fingerprint(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_nanos();
+    Some(format!("{}:{modified}", metadata.len()))
+}
+
+/// Per-user cache location for preflight stamps. Never the shared temp dir, where other
+/// users could plant or redirect stamps.
+fn preflight_cache_dir() -> Option<PathBuf> {
+    let non_empty = |name: &str| env::var_os(name).filter(|value| !value.is_empty());
+    let base = if cfg!(windows) {
+        PathBuf::from(non_empty("LOCALAPPDATA")?)
+    } else {
+        non_empty("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".cache")))?
+    };
+    Some(base.join("signaler").join("preflight"))
+}
+
+/// Writes through a fresh temp file and renames it into place, so an existing file or
+/// link at the stamp path is replaced rather than written through. Failures only cost
+/// a repeated preflight next time, so they are ignored.
+fn write_stamp(stamp_path: &Path, key: &str) {
+    let Some(dir) = stamp_path.parent() else {
+        return;
+    };
+    if fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    let temp_path = stamp_path.with_extension(format!("{}.tmp", std::process::id()));
+    let written = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp_path)
+        .and_then(|mut file| file.write_all(key.as_bytes()))
+        .and_then(|()| fs::rename(&temp_path, stamp_path));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+}
+
+/// FNV-1a, used for stamp file names because it is stable across Rust releases.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn exit_code_for(status: ExitStatus) -> i32 {
+    if status.success() {
+        return 0;
+    }
+    match status.code() {
+        // Windows exit codes are 32-bit (e.g. NTSTATUS values) and pass through unchanged.
+        Some(code) if cfg!(windows) => code,
+        Some(code) if (1..=255).contains(&code) => code,
+        Some(_) => 1,
+        None => signal_exit_code(status),
+    }
+}
+
+#[cfg(unix)]
+fn signal_exit_code(status: ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+    // Mirror the shell convention so scripts can tell a killed engine from a failed one.
+    status.signal().map_or(1, |signal| 128 + signal)
+}
+
+#[cfg(not(unix))]
+fn signal_exit_code(_status: ExitStatus) -> i32 {
+    1
+}
+
+#[instrument(level = "debug")]
 fn resolve_install_root() -> Result<PathBuf, String> {
     if let Ok(value) = env::var("SIGNALER_INSTALL_ROOT") {
         let trimmed = value.trim();
         if !trimmed.is_empty() {
             let path = PathBuf::from(trimmed);
             if path.join("dist").join("bin.js").is_file() {
+                debug!(path = %path.display(), "using SIGNALER_INSTALL_ROOT");
                 return Ok(path);
             }
+            debug!(path = %path.display(), "ignoring SIGNALER_INSTALL_ROOT without dist/bin.js");
         }
     }
 
@@ -66,8 +290,10 @@ fn resolve_install_root() -> Result<PathBuf, String> {
 
     for candidate in candidates {
         if candidate.join("dist").join("bin.js").is_file() {
+            debug!(path = %candidate.display(), "resolved install root");
             return Ok(candidate);
         }
+        debug!(path = %candidate.display(), "no dist/bin.js under candidate");
     }
 
     Err(format!(
@@ -76,6 +302,7 @@ fn resolve_install_root() -> Result<PathBuf, String> {
     ))
 }
 
+#[instrument(level = "debug")]
 fn find_node_executable() -> Result<String, String> {
     let node = if cfg!(windows) { "node.exe" } else { "node" };
     let output = Command::new(if cfg!(windows) { "where" } else { "which" })
@@ -91,5 +318,89 @@ fn find_node_executable() -> Result<String, String> {
         .map(str::trim)
         .find(|line| !line.is_empty())
         .ok_or_else(|| "Node.js was not found on PATH (requires Node 18+)".to_string())?;
+    debug!(node = first, "located Node.js");
     Ok(first.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!(
+            "signaler-launcher-test-{}-{name}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exit_code_mirrors_child_status() {
+        use std::os::unix::process::ExitStatusExt;
+        // Raw wait statuses: exit codes live in the high byte, signals in the low bits.
+        assert_eq!(exit_code_for(ExitStatus::from_raw(0)), 0);
+        assert_eq!(exit_code_for(ExitStatus::from_raw(3 << 8)), 3);
+        assert_eq!(exit_code_for(ExitStatus::from_raw(15)), 143);
+        assert_eq!(exit_code_for(ExitStatus::from_raw(9)), 137);
+    }
+
+    #[test]
+    fn leading_no_preflight_is_consumed() {
+        let (forwarded, skip) = take_launcher_flags(args(&["--no-preflight", "audit", "--json"]));
+        assert!(skip);
+        assert_eq!(forwarded, args(&["audit", "--json"]));
+    }
+
+    #[test]
+    fn no_preflight_after_command_or_separator_is_forwarded() {
+        let input = args(&["a", "--", "--no-preflight", "b"]);
+        let (forwarded, skip) = take_launcher_flags(input.clone());
+        assert!(!skip);
+        assert_eq!(forwarded, input);
+
+        let input = args(&["audit", "--no-preflight"]);
+        let (forwarded, skip) = take_launcher_flags(input.clone());
+        assert!(!skip);
+        assert_eq!(forwarded, input);
+    }
+
+    #[test]
+    fn empty_entrypoint_fails_preflight_without_spawning_node() {
+        let dir = scratch_dir("empty");
+        let bin_js = dir.join("bin.js");
+        fs::write(&bin_js, "").unwrap();
+        let result = preflight_entrypoint("missing-node-binary", &bin_js);
+        assert!(matches!(result, Err(LaunchError::CorruptEntrypoint(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn stamp_key_tracks_entrypoint_length_and_mtime() {
+        let dir = scratch_dir("stamp-key");
+        let bin_js = dir.join("bin.js");
+        let node = dir.join("node");
+        fs::write(&bin_js, "1").unwrap();
+        fs::write(&node, "node").unwrap();
+        let original = preflight_stamp_key(&node, &bin_js).unwrap();
+        assert_eq!(preflight_stamp_key(&node, &bin_js).unwrap(), original);
+
+        let file = fs::File::options().write(true).open(&bin_js).unwrap();
+        let modified = file.metadata().unwrap().modified().unwrap();
+        file.set_len(2).unwrap();
+        file.set_modified(modified).unwrap();
+        let resized = preflight_stamp_key(&node, &bin_js).unwrap();
+        assert_ne!(resized, original);
+
+        file.set_modified(modified + std::time::Duration::from_secs(60))
+            .unwrap();
+        assert_ne!(preflight_stamp_key(&node, &bin_js).unwrap(), resized);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}